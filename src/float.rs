@@ -0,0 +1,160 @@
+//! Provides IEEE-754 floating point bit-pattern inspection.
+
+use colored::Colorize;
+
+use crate::format::FormatBits;
+use crate::int::{Integer, IntegerOptions};
+
+/// Describes the bit layout of an IEEE-754 floating point format.
+#[derive(Copy, Clone, Debug)]
+struct FloatFormat {
+    /// Total number of bits.
+    bits: usize,
+    /// Number of exponent bits.
+    exponent_bits: usize,
+    /// Number of stored significand (mantissa) bits.
+    mantissa_bits: usize,
+    /// The exponent bias.
+    bias: i64,
+}
+
+impl FloatFormat {
+    /// IEEE-754 binary32 (`f32`): 1 sign bit, 8 exponent bits, 23 mantissa bits.
+    const F32: Self = Self {
+        bits: 32,
+        exponent_bits: 8,
+        mantissa_bits: 23,
+        bias: 127,
+    };
+
+    /// IEEE-754 binary64 (`f64`): 1 sign bit, 11 exponent bits, 52 mantissa bits.
+    const F64: Self = Self {
+        bits: 64,
+        exponent_bits: 11,
+        mantissa_bits: 52,
+        bias: 1023,
+    };
+
+    /// Returns the format for the given total bit width (32 or 64).
+    fn for_bits(bits: usize) -> Self {
+        if bits == 32 {
+            Self::F32
+        } else {
+            Self::F64
+        }
+    }
+
+    /// The maximum (all-ones) biased exponent value for this format.
+    fn max_biased_exponent(&self) -> u64 {
+        (1 << self.exponent_bits) - 1
+    }
+}
+
+/// The IEEE-754 classification of a floating point value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum FloatClass {
+    Zero,
+    Subnormal,
+    Normal,
+    Infinite,
+    NaN,
+}
+
+impl std::fmt::Display for FloatClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Zero => "zero",
+            Self::Subnormal => "subnormal",
+            Self::Normal => "normal",
+            Self::Infinite => "infinite",
+            Self::NaN => "NaN",
+        })
+    }
+}
+
+/// Parses `str` as a floating point value of the given width (32 or 64) and
+/// prints a structured breakdown of its sign, exponent, and significand bits.
+///
+/// `str` is parsed as a decimal float (e.g. `3.14`, `-0.0`, `inf`) unless it
+/// starts with `0x`/`0X`, in which case it is read as the hexadecimal bit
+/// pattern of the float directly (e.g. `0x3ff0000000000000` for `1.0f64`).
+pub fn print_float_info(str: &str, bits: usize) -> Result<(), Box<dyn std::error::Error>> {
+    let format = FloatFormat::for_bits(bits);
+    let raw_bits = parse_bits(str, format)?;
+
+    let sign = raw_bits >> (format.bits - 1) & 1 == 1;
+    let biased_exponent = raw_bits >> format.mantissa_bits & format.max_biased_exponent();
+    let mantissa = raw_bits & ((1 << format.mantissa_bits) - 1);
+
+    let is_exponent_zero = biased_exponent == 0;
+    let is_exponent_max = biased_exponent == format.max_biased_exponent();
+    let is_mantissa_zero = mantissa == 0;
+
+    let class = match (is_exponent_zero, is_exponent_max, is_mantissa_zero) {
+        (true, _, true) => FloatClass::Zero,
+        (true, _, false) => FloatClass::Subnormal,
+        (_, true, true) => FloatClass::Infinite,
+        (_, true, false) => FloatClass::NaN,
+        _ => FloatClass::Normal,
+    };
+
+    let implicit_leading_one = !is_exponent_zero;
+    let unbiased_exponent = biased_exponent as i64 - format.bias;
+
+    let exponent_int = field_integer(biased_exponent, format.exponent_bits);
+    let mantissa_int = field_integer(mantissa, format.mantissa_bits);
+
+    println!(
+        "Sign            =>  {}",
+        if sign { "-".red() } else { "+".green() }
+    );
+    println!(
+        "Exponent (raw)  =>  {} ({}{}, {}{})",
+        biased_exponent.to_string().blue(),
+        "0b".yellow(),
+        exponent_int.binary_string().blue(),
+        "0x".purple(),
+        exponent_int.hex_string().blue(),
+    );
+    println!(
+        "Exponent        =>  {}",
+        unbiased_exponent.to_string().blue()
+    );
+    println!(
+        "Mantissa        =>  {}{}, {}{}",
+        "0b".yellow(),
+        mantissa_int.binary_string().blue(),
+        "0x".purple(),
+        mantissa_int.hex_string().blue(),
+    );
+    println!(
+        "Implicit 1      =>  {}",
+        implicit_leading_one.to_string().blue()
+    );
+    println!("Class           =>  {}", class.to_string().cyan().bold());
+
+    Ok(())
+}
+
+/// Parses `str` into the raw bit pattern of a `bits`-wide float, per
+/// [`print_float_info`]'s rules.
+fn parse_bits(str: &str, format: FloatFormat) -> Result<u64, Box<dyn std::error::Error>> {
+    if let Some(hex) = str.strip_prefix("0x").or_else(|| str.strip_prefix("0X")) {
+        return Ok(u64::from_str_radix(hex, 16)?);
+    }
+
+    Ok(if format.bits == 32 {
+        str.parse::<f32>()?.to_bits() as u64
+    } else {
+        str.parse::<f64>()?.to_bits()
+    })
+}
+
+/// Wraps a field's raw value into an [`Integer`] of its own bit width, so it can
+/// be rendered with [`FormatBits`].
+fn field_integer(value: u64, width: usize) -> Integer<u64> {
+    Integer::new(
+        &[value],
+        IntegerOptions::new(false, width, Some(width), false),
+    )
+}
@@ -4,7 +4,7 @@ use std::fmt::{Binary, Display, LowerHex, Octal, UpperHex};
 
 use bitvec::{slice::BitSlice, store::BitStore};
 
-use crate::int::{int_from_slice, Integer};
+use crate::int::Integer;
 
 impl<T: BitStore> Display for Integer<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -16,100 +16,278 @@ impl<T: BitStore> Display for Integer<T> {
             &self.bits
         };
 
-        if self.is_negative() {
-            // (-(num_from_slice::<u64, _>(slice) as i64)).fmt(f)
-            Display::fmt(&-(int_from_slice::<u64, _>(slice) as i64), f)
-        } else {
-            Display::fmt(&int_from_slice::<u64, _>(slice), f)
-        }
+        let digits: String = decimal_digits(slice)
+            .into_iter()
+            .map(|d| (b'0' + d) as char)
+            .collect();
+        pad(f, self.is_negative(), "", &digits)
     }
 }
 
 impl<T: BitStore> Binary for Integer<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.bits
-            .iter()
-            .rev()
-            .map(|b| if *b { '1' } else { '0' })
-            .collect::<String>()
-            .fmt(f)
+        pad(f, false, "0b", &format_in_radix::<_, Base2>(&self.bits))
     }
 }
 
 impl<T: BitStore> Octal for Integer<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.bits
-            .chunks(3)
-            .map(|c| bits_to_char(c, 8))
-            .rev()
-            .collect::<String>()
-            .fmt(f)
+        pad(f, false, "0o", &format_in_radix::<_, Base8>(&self.bits))
     }
 }
 
 impl<T: BitStore> LowerHex for Integer<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.bits
-            .chunks(4)
-            .map(|c| bits_to_char(c, 16))
-            .rev()
-            .collect::<String>()
-            .fmt(f)
+        pad(f, false, "0x", &format_in_radix::<_, Base16>(&self.bits))
     }
 }
 
 impl<T: BitStore> UpperHex for Integer<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.bits
-            .chunks(4)
-            .map(|c| bits_to_char(c, 16).to_ascii_lowercase())
-            .rev()
-            .collect::<String>()
-            .fmt(f)
+        pad(
+            f,
+            false,
+            "0x",
+            &format_in_radix::<_, Base16>(&self.bits).to_ascii_uppercase(),
+        )
     }
 }
 
+/// Applies the standard numeric formatting flags (width, fill, alignment, `+`/`-`
+/// sign, the `#` alternate flag, and zero-padding) to an already-rendered digit
+/// string, the same way the primitive integer types do.
+///
+/// `prefix` (e.g. `"0b"`/`"0o"`/`"0x"`) is only written when `f.alternate()` is
+/// set, and zero-padding (`f.sign_aware_zero_pad()`) is inserted between the
+/// sign/prefix and `digits`, matching `{:08b}`, `{:#x}`, `{:+}`, etc.
+fn pad(
+    f: &mut std::fmt::Formatter<'_>,
+    is_negative: bool,
+    prefix: &str,
+    digits: &str,
+) -> std::fmt::Result {
+    f.pad_integral(!is_negative, prefix, digits)
+}
+
 pub trait FormatBits {
     /// Returns a binary representation of the bits.
     fn binary_string(&self) -> String;
 
-    /// Returns an octal representation of the bits.
-    fn octal_string(&self) -> String;
-
     /// Returns a hex representation of the bits.
     fn hex_string(&self) -> String;
 }
 
 impl<T: BitStore> FormatBits for Integer<T> {
     fn binary_string(&self) -> String {
-        self.bits
-            .iter()
-            .rev()
-            .map(|b| if *b { '1' } else { '0' })
-            .collect()
+        format_in_radix::<_, Base2>(&self.bits)
     }
 
-    fn octal_string(&self) -> String {
-        self.bits
-            .chunks(3)
-            .map(|c| bits_to_char(c, 8))
-            .rev()
-            .collect()
+    fn hex_string(&self) -> String {
+        format_in_radix::<_, Base16>(&self.bits)
     }
+}
 
-    fn hex_string(&self) -> String {
-        self.bits
-            .chunks(4)
-            .map(|c| bits_to_char(c, 16))
-            .rev()
-            .collect()
+/// A radix (base) in which an [`Integer`]'s bits can be rendered, known at compile time.
+///
+/// Implementing this trait and calling [`format_in_radix`] is what the binary, octal,
+/// and hex `fmt` impls above are built from; [`format_in_dyn_radix`] provides the same
+/// digit-extraction routine for bases that are only known at runtime.
+pub trait GenericRadix {
+    /// The base of the radix, between 2 and 36 (inclusive).
+    const BASE: u32;
+
+    /// The number of bits a single digit of this radix covers (`BASE` is a power of
+    /// two), used to zero-pad a rendering out to the full width of the source bits.
+    const BITS_PER_DIGIT: u32;
+
+    /// Maps a digit value in `0..BASE` to its ASCII representation
+    /// (`0`-`9`, then `a`-`z`).
+    fn digit(value: u8) -> u8 {
+        digit_char(value) as u8
     }
 }
 
-/// Converts the bitslice into a character based on the given radix.
+/// Base 2 (binary).
+pub struct Base2;
+
+impl GenericRadix for Base2 {
+    const BASE: u32 = 2;
+    const BITS_PER_DIGIT: u32 = 1;
+}
+
+/// Base 8 (octal).
+pub struct Base8;
+
+impl GenericRadix for Base8 {
+    const BASE: u32 = 8;
+    const BITS_PER_DIGIT: u32 = 3;
+}
+
+/// Base 16 (hexadecimal).
+pub struct Base16;
+
+impl GenericRadix for Base16 {
+    const BASE: u32 = 16;
+    const BITS_PER_DIGIT: u32 = 4;
+}
+
+/// Formats the bits of an integer in the given [`GenericRadix`], zero-padded out to
+/// the full width of `bits` (`ceil(bits.len() / R::BITS_PER_DIGIT)` digits), matching
+/// the fixed-width rendering a bit-pattern inspection tool is expected to show.
+pub fn format_in_radix<T: BitStore, R: GenericRadix>(bits: &BitSlice<T>) -> String {
+    let digits: String = radix_digits(bits, R::BASE)
+        .into_iter()
+        .map(|d| R::digit(d) as char)
+        .collect();
+
+    let width = (bits.len() as u32).div_ceil(R::BITS_PER_DIGIT);
+    format!("{:0>width$}", digits, width = width as usize)
+}
+
+/// Formats the bits of an integer in an arbitrary base between 2 and 36.
+///
+/// Unlike [`format_in_radix`], `base` is a runtime value rather than a type parameter,
+/// which is what powers the `--display-radix` flag.
+///
+/// Panics if `base` is not between 2 and 36 (inclusive).
+pub fn format_in_dyn_radix<T: BitStore>(bits: &BitSlice<T>, base: u32) -> String {
+    assert!((2..=36).contains(&base), "base must be between 2 and 36");
+
+    radix_digits(bits, base)
+        .into_iter()
+        .map(digit_char)
+        .collect()
+}
+
+/// Inserts `_` digit-grouping separators into an already-rendered digit string,
+/// every `group` characters counting from the least-significant (rightmost) side.
 ///
-/// The length of the bitslice should be appropriate for the radix. For instance,
-/// the length should be at most 3 for radix 8 (octal), and 4 for radix 16 (hex).
-fn bits_to_char<T: BitStore>(slice: &BitSlice<T>, radix: u32) -> char {
-    char::from_digit(int_from_slice(slice), radix).expect("bitslice of valid length")
+/// A leading `-` sign, if present, is left in place and not counted towards a
+/// group. Composes with any radix, since it operates on the rendered string
+/// rather than the bits themselves.
+pub fn group_digits(digits: &str, group: usize) -> String {
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", digits),
+    };
+
+    let mut grouped: Vec<char> = Vec::with_capacity(digits.len() + digits.len() / group);
+
+    for (i, ch) in digits.chars().rev().enumerate() {
+        if i > 0 && i % group == 0 {
+            grouped.push('_');
+        }
+
+        grouped.push(ch);
+    }
+
+    grouped.reverse();
+    format!("{}{}", sign, grouped.into_iter().collect::<String>())
+}
+
+/// Maps a digit value in `0..36` to its ASCII representation (`0`-`9`, then `a`-`z`).
+fn digit_char(value: u8) -> char {
+    match value {
+        0..=9 => (b'0' + value) as char,
+        _ => (b'a' + value - 10) as char,
+    }
+}
+
+/// Repeatedly divides `bits` (treated as an unsigned magnitude) by `base`, collecting
+/// each remainder as a digit value, until the magnitude reaches zero.
+///
+/// The returned digits are most-significant-first. This operates directly on the
+/// [`BitSlice`] rather than converting it to a `u64` first, so it works for integers
+/// of any size.
+fn radix_digits<T: BitStore>(bits: &BitSlice<T>, base: u32) -> Vec<u8> {
+    let mut magnitude = bits.to_bitvec();
+
+    if magnitude.not_any() {
+        return vec![0];
+    }
+
+    let mut digits = Vec::new();
+
+    while magnitude.any() {
+        let mut remainder = 0_u32;
+
+        for mut bit in magnitude.iter_mut().rev() {
+            let dividend = (remainder << 1) | (*bit as u32);
+            remainder = dividend % base;
+            *bit = dividend / base != 0;
+        }
+
+        digits.push(remainder as u8);
+    }
+
+    digits.reverse();
+    digits
+}
+
+/// Converts `bits` (treated as an unsigned magnitude) into its decimal digits, most
+/// significant first, using the double-dabble (shift-and-add-3) algorithm.
+///
+/// Like [`radix_digits`], this never converts `bits` to a primitive integer, so it
+/// works for bit vectors of any length rather than being limited to 64 (or however
+/// wide the widest primitive backing store is).
+fn decimal_digits<T: BitStore>(bits: &BitSlice<T>) -> Vec<u8> {
+    let n = bits.len();
+
+    if bits.not_any() {
+        return vec![0];
+    }
+
+    // ceil(n * log10(2)), with a little headroom baked into the approximation of
+    // log10(2) (4/13 ~= 0.3077 vs. the real 0.30103), to be safe against rounding.
+    let nibble_count = n * 4 / 13 + 1;
+
+    // A scratch register, least-significant bit first: the low `n` bits hold the
+    // (shrinking) remainder of the source number, and the upper `nibble_count * 4`
+    // bits hold the BCD digits being built up.
+    let mut scratch = vec![false; n + nibble_count * 4];
+    for (dst, src) in scratch[..n].iter_mut().zip(bits.iter()) {
+        *dst = *src;
+    }
+
+    for _ in 0..n {
+        for nibble in 0..nibble_count {
+            let start = n + nibble * 4;
+            let value = nibble_value(&scratch[start..start + 4]);
+
+            if value >= 5 {
+                set_nibble(&mut scratch[start..start + 4], value + 3);
+            }
+        }
+
+        for i in (1..scratch.len()).rev() {
+            scratch[i] = scratch[i - 1];
+        }
+        scratch[0] = false;
+    }
+
+    let mut digits: Vec<u8> = (0..nibble_count)
+        .rev()
+        .map(|nibble| nibble_value(&scratch[n + nibble * 4..n + nibble * 4 + 4]))
+        .collect();
+
+    while digits.len() > 1 && digits[0] == 0 {
+        digits.remove(0);
+    }
+
+    digits
+}
+
+/// Reads a 4-bit, least-significant-bit-first nibble as a value in `0..16`.
+fn nibble_value(nibble: &[bool]) -> u8 {
+    nibble
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, bit)| acc | ((*bit as u8) << i))
+}
+
+/// Writes `value` (`0..16`) into a 4-bit, least-significant-bit-first nibble.
+fn set_nibble(nibble: &mut [bool], value: u8) {
+    for (i, bit) in nibble.iter_mut().enumerate() {
+        *bit = (value >> i) & 1 == 1;
+    }
 }
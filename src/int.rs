@@ -1,9 +1,8 @@
 //! Provides an arbitrary-length integer abstraction over a bit vector.
 
-use std::mem::size_of;
+use std::mem::size_of_val;
 
 use bitvec::prelude::*;
-use num_traits::{cast, PrimInt};
 
 /// Options to create an [`Integer`].
 #[derive(Copy, Clone, Debug, Default)]
@@ -46,13 +45,25 @@ pub struct Integer<T: BitStore> {
 }
 
 impl<T: BitStore> Integer<T> {
-    /// Creates an arbitrary length [`Integer`] from a number and provided options.
+    /// Creates an arbitrary length [`Integer`] from its little-endian limbs and
+    /// provided options.
     ///
-    /// Panics if the number of bits in `elem` are less than the provided size.
-    pub fn new(mut elem: T, options: IntegerOptions) -> Self {
-        assert!(size_of::<T>() * 8 >= options.size);
-
-        let bitslice = elem.view_bits_mut::<Lsb0>();
+    /// `elems` holds the integer's limbs, least-significant first. Taking several
+    /// limbs rather than a single `T` is what lets the integer grow past the width
+    /// of a single backing element (e.g. a 128-bit value as two `u64` limbs).
+    ///
+    /// Panics if the total number of bits across `elems` is less than the provided
+    /// size.
+    pub fn new(elems: &[T], options: IntegerOptions) -> Self
+    where
+        T: Copy,
+    {
+        assert!(size_of_val(elems) * 8 >= options.size);
+
+        let mut bitslice = BitVec::<T::Unalias>::with_capacity(size_of_val(elems) * 8);
+        for mut elem in elems.iter().copied() {
+            bitslice.extend_from_bitslice(&elem.view_bits_mut::<Lsb0>().to_bitvec());
+        }
 
         let significant_bits = options
             .significant_bits
@@ -64,7 +75,8 @@ impl<T: BitStore> Integer<T> {
             }
         }
 
-        let bits = bitslice[..options.size].to_bitvec();
+        bitslice.truncate(options.size);
+        let bits = bitslice;
 
         Self {
             negative: options.signed && bits.last().map(|f| *f).unwrap_or_default(),
@@ -102,19 +114,6 @@ impl<T: BitStore> Integer<T> {
     }
 }
 
-/// Converts the given bitslice into an integer.
-pub(crate) fn int_from_slice<T: PrimInt + std::ops::BitOrAssign, U: BitStore>(
-    slice: &BitSlice<U>,
-) -> T {
-    let mut num = T::zero();
-
-    for (idx, bit) in slice.iter().enumerate() {
-        num |= cast::<u8, T>(*bit as u8).unwrap() << idx;
-    }
-
-    num
-}
-
 /// Converts the bitslice into its two's complement.
 fn make_slice_twos_complement<T: BitStore>(slice: &mut BitSlice<T>) {
     if let Some(first_one) = slice.first_one() {
@@ -1,3 +1,4 @@
+mod float;
 mod format;
 mod int;
 mod parse;
@@ -6,7 +7,12 @@ use clap::{AppSettings, ArgGroup, Parser};
 use colored::Colorize;
 use int::{Integer, IntegerOptions};
 
-const MAX_BITS: usize = u64::BITS as usize;
+/// The largest bit width the CLI can inspect.
+///
+/// [`Integer`] itself accepts any number of backing limbs, but the
+/// construction below always passes exactly two `u64` limbs, so in practice
+/// inputs are capped at 128 bits rather than limited only by memory.
+const MAX_BITS: usize = u128::BITS as usize;
 
 const HELP_TEMPLATE: &str = r"{before-help}{bin} {version}
 {author}
@@ -36,6 +42,11 @@ const HELP_TEMPLATE: &str = r"{before-help}{bin} {version}
         .required(false)
         .args(&["zero-extend", "sign-extend"]),
 ))]
+#[clap(group(
+    ArgGroup::new("float-width")
+        .required(false)
+        .args(&["f32", "f64"]),
+))]
 #[clap(setting(AppSettings::AllowHyphenValues))]
 #[clap(setting(AppSettings::DeriveDisplayOrder))]
 #[clap(help_template(HELP_TEMPLATE))]
@@ -75,6 +86,8 @@ struct App {
     ///
     /// The default bit size is equal to the minimum number of bits required
     /// to represent the input.
+    ///
+    /// Must be no more than 128.
     #[clap(short, long)]
     bits: Option<usize>,
     /// Sign-extend the input integer when converting it to a bigger size.
@@ -112,6 +125,30 @@ struct App {
     /// Disabled by default.
     #[clap(short, long, alias = "two")]
     twos_complement: bool,
+    /// Additionally display the integer in the given base.
+    ///
+    /// Must be between 2 and 36 (inclusive).
+    #[clap(long, value_name = "RADIX")]
+    display_radix: Option<u32>,
+    /// Group digits in the output with `_` separators every N digits.
+    ///
+    /// Digits are counted from the least-significant (rightmost) side.
+    #[clap(short, long, value_name = "N")]
+    group: Option<usize>,
+    /// Inspect the input as an IEEE-754 floating point value instead of an integer.
+    ///
+    /// The input is parsed as a decimal float unless it starts with `0x`/`0X`, in
+    /// which case it is read as the float's raw hexadecimal bit pattern.
+    #[clap(long)]
+    float: bool,
+    /// Treat the float input as 32-bit (single precision).
+    #[clap(long)]
+    f32: bool,
+    /// Treat the float input as 64-bit (double precision).
+    ///
+    /// This is the default.
+    #[clap(long)]
+    f64: bool,
 }
 
 /// Returns the (optional) number of bits specified by the user.
@@ -151,29 +188,51 @@ const fn radix(app: &App) -> Option<u32> {
 }
 
 /// Prints information about the integer to the standard output.
-fn print_integer_info(integer: &Integer<u64>) {
-    println!("Decimal         =>  {}", integer.to_string().blue());
+///
+/// If `display_radix` is set, an extra line is printed with the integer
+/// rendered in that base. If `group` is set, digits in every rendered line
+/// are grouped with `_` separators.
+fn print_integer_info(integer: &Integer<u64>, display_radix: Option<u32>, group: Option<usize>) {
+    let grouped = |digits: String| match group {
+        Some(group) => format::group_digits(&digits, group),
+        None => digits,
+    };
+
+    println!("Decimal         =>  {}", grouped(integer.to_string()).blue());
     println!(
         "Binary          =>  {}{}",
         "0b".yellow(),
-        format!("{:b}", integer).blue(),
+        grouped(format!("{:b}", integer)).blue(),
     );
     println!(
         "Octal           =>  {}{}",
         "0o".green(),
-        format!("{:o}", integer).blue(),
+        grouped(format!("{:o}", integer)).blue(),
     );
     println!(
         "Hexadecimal     =>  {}{}",
         "0x".purple(),
-        format!("{:x}", integer).blue(),
+        grouped(format!("{:x}", integer)).blue(),
     );
+
+    if let Some(radix) = display_radix {
+        println!(
+            "{:<16}=>  {}",
+            format!("Base {}", radix),
+            grouped(format::format_in_dyn_radix(&integer.bits, radix)).blue(),
+        );
+    }
 }
 
 /// Runs the app.
 fn run() -> Result<(), Box<dyn std::error::Error>> {
     let app = App::parse();
 
+    if app.float {
+        let float_bits = if app.f32 { 32 } else { 64 };
+        return float::print_float_info(&app.input, float_bits);
+    }
+
     let radix = radix(&app)
         .or_else(|| parse::identify_radix(&app.input))
         .unwrap_or(10);
@@ -187,32 +246,55 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         return Err(format!("number of bits must be less than or equal to {}", MAX_BITS).into());
     }
 
+    if let Some(display_radix) = app.display_radix {
+        if !(2..=36).contains(&display_radix) {
+            return Err(format!(
+                "display radix must be between 2 and 36, got {}",
+                display_radix
+            )
+            .into());
+        }
+    }
+
+    if app.group == Some(0) {
+        return Err("group size must be greater than 0".into());
+    }
+
     let is_negative = app.input.starts_with('-');
 
     let (int, opt_significant_bits) = if is_negative {
-        let (int, _) = parse::parse::<i64>(&app.input, radix)?;
+        let (int, _) = parse::parse::<i128>(&app.input, radix)?;
 
         (
-            int as u64,
-            Some((i64::BITS - int.leading_ones() + 1) as usize),
+            int as u128,
+            Some((i128::BITS - int.leading_ones() + 1) as usize),
         )
     } else {
         parse::parse(&app.input, radix)?
     };
 
     let significant_bits =
-        opt_significant_bits.unwrap_or_else(|| (u64::BITS - int.leading_zeros()) as usize);
+        opt_significant_bits.unwrap_or_else(|| (u128::BITS - int.leading_zeros()) as usize);
+
+    if significant_bits > MAX_BITS {
+        return Err(parse::ParseIntError::Overflow { bits: MAX_BITS }.into());
+    }
 
     let bit_size = total_bits(&app).unwrap_or(significant_bits);
+
+    if significant_bits > bit_size {
+        return Err(parse::ParseIntError::Overflow { bits: bit_size }.into());
+    }
+
     let signed = app.signed || is_negative;
     let sign_extend = !app.zero_extend && (app.sign_extend || signed);
 
     let mut integer = Integer::new(
-        int as u64,
+        &[int as u64, (int >> 64) as u64],
         IntegerOptions::new(signed, bit_size, Some(significant_bits), sign_extend),
     );
 
-    print_integer_info(&integer);
+    print_integer_info(&integer, app.display_radix, app.group);
 
     println!("\nBits: {}", integer.bits().to_string().cyan().bold());
 
@@ -220,7 +302,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         integer.make_twos_complement();
 
         println!("\n{}", "2's Complement \\".bright_cyan().bold());
-        print_integer_info(&integer);
+        print_integer_info(&integer, app.display_radix, app.group);
     }
 
     Ok(())
@@ -1,7 +1,49 @@
 //! Utilities to parse integers from a string.
 
+use std::{fmt, mem::size_of};
+
 use num_traits::PrimInt;
 
+/// An error that can occur while parsing a user-supplied integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseIntError {
+    /// `radix` is not between 2 and 36 (inclusive).
+    InvalidRadix(u32),
+    /// `found`, at the given 0-indexed column of the digit string, is not a
+    /// valid digit for `radix`.
+    InvalidChar { found: char, column: usize, radix: u32 },
+    /// A `_` digit-grouping separator was leading, trailing, or doubled.
+    InvalidSeparators,
+    /// The magnitude does not fit in `bits` bits.
+    Overflow { bits: usize },
+}
+
+impl fmt::Display for ParseIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRadix(radix) => {
+                write!(f, "radix must be between 2 and 36, got {}", radix)
+            }
+            Self::InvalidChar {
+                found,
+                column,
+                radix,
+            } => write!(
+                f,
+                "invalid digit '{}' for base {} at column {}",
+                found, radix, column
+            ),
+            Self::InvalidSeparators => write!(
+                f,
+                "digit-grouping `_` separators cannot be leading, trailing, or doubled"
+            ),
+            Self::Overflow { bits } => write!(f, "value does not fit in {} bits", bits),
+        }
+    }
+}
+
+impl std::error::Error for ParseIntError {}
+
 /// Parses the given string into an integer of the specified radix.
 ///
 /// Returns the parsed integer and an optional number of significant bits in the
@@ -9,22 +51,75 @@ use num_traits::PrimInt;
 ///
 /// The number of significant bits is only returned when input is a binary, octal,
 /// or hexadecimal string.
-pub fn parse<I: PrimInt>(str: &str, radix: u32) -> Result<(I, Option<usize>), I::FromStrRadixErr> {
-    let parse_with_sig_bits = |prefix: &str| -> Result<(I, Option<usize>), I::FromStrRadixErr> {
+///
+/// Digit-grouping `_` separators (e.g. `0xDEAD_BEEF`, `1_000_000`) are allowed
+/// between digits and are stripped before parsing; a leading, trailing, or
+/// doubled separator is rejected. Every remaining character is validated against
+/// `radix` before conversion, so an invalid digit is reported with its position
+/// rather than a generic parse failure.
+pub fn parse<I: PrimInt>(str: &str, radix: u32) -> Result<(I, Option<usize>), ParseIntError> {
+    if !(2..=36).contains(&radix) {
+        return Err(ParseIntError::InvalidRadix(radix));
+    }
+
+    let parse_with_sig_bits = |prefix: &str| -> Result<(I, Option<usize>), ParseIntError> {
         let trimmed = str
             .trim_start_matches(prefix)
             .trim_start_matches(&prefix.to_ascii_uppercase());
-        I::from_str_radix(trimmed, radix).map(|i| (i, Some(trimmed.len())))
+        let digits = strip_separators(trimmed)?;
+
+        from_validated_radix(&digits, radix).map(|i| (i, Some(digits.len())))
     };
 
     match radix {
         2 => parse_with_sig_bits("0b"),
         8 => parse_with_sig_bits("0o").map(|(i, b)| (i, Some(b.unwrap() * 3))),
         16 => parse_with_sig_bits("0x").map(|(i, b)| (i, Some(b.unwrap() * 4))),
-        _ => I::from_str_radix(str, radix).map(|i| (i, None)),
+        _ => {
+            let digits = strip_separators(str)?;
+
+            from_validated_radix(&digits, radix).map(|i| (i, None))
+        }
     }
 }
 
+/// Strips `_` digit-grouping separators from `str`.
+///
+/// Returns an error if `str` has a leading, trailing, or doubled separator.
+fn strip_separators(str: &str) -> Result<String, ParseIntError> {
+    if str.starts_with('_') || str.ends_with('_') || str.contains("__") {
+        return Err(ParseIntError::InvalidSeparators);
+    }
+
+    Ok(str.replace('_', ""))
+}
+
+/// Validates that every character in `digits` is a legal digit for `radix` (a
+/// leading `-`/`+` sign is allowed), then parses it.
+///
+/// Returns [`ParseIntError::InvalidChar`] pointing at the first illegal
+/// character, or [`ParseIntError::Overflow`] if the digits are all legal but the
+/// magnitude doesn't fit in `I`.
+fn from_validated_radix<I: PrimInt>(digits: &str, radix: u32) -> Result<I, ParseIntError> {
+    for (column, ch) in digits.chars().enumerate() {
+        if column == 0 && matches!(ch, '-' | '+') {
+            continue;
+        }
+
+        if !ch.is_digit(radix) {
+            return Err(ParseIntError::InvalidChar {
+                found: ch,
+                column,
+                radix,
+            });
+        }
+    }
+
+    I::from_str_radix(digits, radix).map_err(|_| ParseIntError::Overflow {
+        bits: size_of::<I>() * 8,
+    })
+}
+
 /// Tries to identify radix of the integer string.
 ///
 /// The first two characters of the string are used to determine the radix.